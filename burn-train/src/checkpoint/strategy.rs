@@ -0,0 +1,277 @@
+use std::collections::HashSet;
+
+use crate::metric::store::{Aggregate, Direction, EventStoreClient, Split};
+
+/// An action to perform on a checkpoint, returned by a
+/// [checkpointing strategy](CheckpointingStrategy).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckpointingAction {
+    /// Save the checkpoint for the current epoch.
+    Save,
+    /// Delete the checkpoint saved for the given epoch.
+    Delete(usize),
+}
+
+/// A strategy consulted by the [learner checkpointer](crate::LearnerCheckpointer) at every
+/// epoch to decide which checkpoints to save and delete.
+pub trait CheckpointingStrategy {
+    /// Returns the [actions](CheckpointingAction) to perform for the given `epoch`.
+    fn checkpointing(
+        &mut self,
+        epoch: usize,
+        store: &EventStoreClient,
+    ) -> Vec<CheckpointingAction>;
+}
+
+/// Keeps the checkpoint with the best value for a monitored metric, deleting all others.
+pub struct MetricCheckpointingStrategy {
+    name: String,
+    aggregate: Aggregate,
+    direction: Direction,
+    split: Split,
+    epochs_seen: Vec<usize>,
+}
+
+impl MetricCheckpointingStrategy {
+    /// Create a new metric checkpointing strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the metric to monitor.
+    /// * `aggregate` - How the metric's values are aggregated over an epoch.
+    /// * `direction` - Whether a lower or higher aggregated value is better.
+    /// * `split` - The split the metric is monitored on.
+    pub fn new(name: &str, aggregate: Aggregate, direction: Direction, split: Split) -> Self {
+        Self {
+            name: name.to_string(),
+            aggregate,
+            direction,
+            split,
+            epochs_seen: Vec::new(),
+        }
+    }
+}
+
+impl CheckpointingStrategy for MetricCheckpointingStrategy {
+    fn checkpointing(
+        &mut self,
+        epoch: usize,
+        store: &EventStoreClient,
+    ) -> Vec<CheckpointingAction> {
+        self.epochs_seen.push(epoch);
+
+        let best_epoch = store.find_epoch(&self.name, self.aggregate, self.direction, self.split);
+
+        let mut actions = vec![CheckpointingAction::Save];
+        for &seen in self.epochs_seen.iter() {
+            if Some(seen) != best_epoch {
+                actions.push(CheckpointingAction::Delete(seen));
+            }
+        }
+        self.epochs_seen.retain(|&seen| Some(seen) == best_epoch);
+
+        actions
+    }
+}
+
+/// Keeps only the checkpoints saved for the last `num_keep` epochs.
+pub struct KeepLastNStrategy {
+    num_keep: usize,
+    epochs_seen: Vec<usize>,
+}
+
+impl KeepLastNStrategy {
+    /// Create a new strategy that keeps the last `num_keep` checkpoints.
+    pub fn new(num_keep: usize) -> Self {
+        Self {
+            num_keep,
+            epochs_seen: Vec::new(),
+        }
+    }
+}
+
+impl CheckpointingStrategy for KeepLastNStrategy {
+    fn checkpointing(
+        &mut self,
+        epoch: usize,
+        _store: &EventStoreClient,
+    ) -> Vec<CheckpointingAction> {
+        self.epochs_seen.push(epoch);
+
+        let mut actions = vec![CheckpointingAction::Save];
+        while self.epochs_seen.len() > self.num_keep {
+            actions.push(CheckpointingAction::Delete(self.epochs_seen.remove(0)));
+        }
+
+        actions
+    }
+}
+
+/// Composes multiple [checkpointing strategies](CheckpointingStrategy): every `Save` action is
+/// kept, and an epoch is only deleted once *every* child strategy has, at some point, voted to
+/// delete it. Children don't necessarily vote to delete the same epoch on the same call, so
+/// votes are accumulated per child across epochs until they agree.
+#[derive(Default)]
+pub struct ComposedCheckpointingStrategy {
+    strategies: Vec<Box<dyn CheckpointingStrategy>>,
+    /// The epochs each child strategy has voted to delete so far, not yet acted upon.
+    delete_votes: Vec<HashSet<usize>>,
+}
+
+impl ComposedCheckpointingStrategy {
+    /// Create a new composed strategy from the given child strategies.
+    pub fn new(strategies: Vec<Box<dyn CheckpointingStrategy>>) -> Self {
+        let delete_votes = strategies.iter().map(|_| HashSet::new()).collect();
+
+        Self {
+            strategies,
+            delete_votes,
+        }
+    }
+}
+
+impl CheckpointingStrategy for ComposedCheckpointingStrategy {
+    fn checkpointing(
+        &mut self,
+        epoch: usize,
+        store: &EventStoreClient,
+    ) -> Vec<CheckpointingAction> {
+        let mut save = false;
+
+        for (strategy, votes) in self.strategies.iter_mut().zip(self.delete_votes.iter_mut()) {
+            for action in strategy.checkpointing(epoch, store) {
+                match action {
+                    CheckpointingAction::Save => save = true,
+                    CheckpointingAction::Delete(epoch) => {
+                        votes.insert(epoch);
+                    }
+                }
+            }
+        }
+
+        let mut actions = Vec::new();
+        if save {
+            actions.push(CheckpointingAction::Save);
+        }
+
+        if let Some((first, rest)) = self.delete_votes.split_first() {
+            let unanimous: Vec<usize> = first
+                .iter()
+                .copied()
+                .filter(|epoch| rest.iter().all(|votes| votes.contains(epoch)))
+                .collect();
+
+            for epoch in unanimous {
+                actions.push(CheckpointingAction::Delete(epoch));
+                for votes in self.delete_votes.iter_mut() {
+                    votes.remove(&epoch);
+                }
+            }
+        }
+
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::store::{Event, NumericEntry};
+
+    fn record(store: &EventStoreClient, epoch: usize, value: f64) {
+        store.add_event(Event::MetricsUpdate {
+            metric_name: "loss".to_string(),
+            split: Split::Valid,
+            epoch,
+            aggregate: NumericEntry::Value(value),
+        });
+    }
+
+    #[test]
+    fn keep_last_n_deletes_old_epochs() {
+        let store = EventStoreClient::default();
+        let mut strategy = KeepLastNStrategy::new(2);
+
+        assert_eq!(
+            strategy.checkpointing(1, &store),
+            vec![CheckpointingAction::Save]
+        );
+        assert_eq!(
+            strategy.checkpointing(2, &store),
+            vec![CheckpointingAction::Save]
+        );
+        assert_eq!(
+            strategy.checkpointing(3, &store),
+            vec![CheckpointingAction::Save, CheckpointingAction::Delete(1)]
+        );
+    }
+
+    #[test]
+    fn metric_strategy_keeps_best_epoch_only() {
+        let store = EventStoreClient::default();
+
+        let mut strategy = MetricCheckpointingStrategy::new(
+            "loss",
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Valid,
+        );
+
+        // Metrics are recorded for an epoch before the checkpointer is consulted for it, just
+        // like during real training.
+        record(&store, 1, 0.5);
+        assert_eq!(
+            strategy.checkpointing(1, &store),
+            vec![CheckpointingAction::Save]
+        );
+
+        record(&store, 2, 0.3);
+        assert_eq!(
+            strategy.checkpointing(2, &store),
+            vec![CheckpointingAction::Save, CheckpointingAction::Delete(1)]
+        );
+
+        record(&store, 3, 0.4);
+        assert_eq!(
+            strategy.checkpointing(3, &store),
+            vec![CheckpointingAction::Save, CheckpointingAction::Delete(3)]
+        );
+    }
+
+    #[test]
+    fn composed_strategy_only_deletes_once_every_child_agrees() {
+        let store = EventStoreClient::default();
+
+        let mut strategy = ComposedCheckpointingStrategy::new(vec![
+            Box::new(KeepLastNStrategy::new(1)),
+            Box::new(MetricCheckpointingStrategy::new(
+                "loss",
+                Aggregate::Mean,
+                Direction::Lowest,
+                Split::Valid,
+            )),
+        ]);
+
+        // Epoch 1: nothing to delete yet.
+        record(&store, 1, 0.5);
+        assert_eq!(
+            strategy.checkpointing(1, &store),
+            vec![CheckpointingAction::Save]
+        );
+
+        // Epoch 2: both children want epoch 1 gone (it fell out of the window and it's no
+        // longer the best metric value), so the composed strategy deletes it too.
+        record(&store, 2, 0.3);
+        let actions = strategy.checkpointing(2, &store);
+        assert!(actions.contains(&CheckpointingAction::Save));
+        assert!(actions.contains(&CheckpointingAction::Delete(1)));
+
+        // Epoch 3: `KeepLastNStrategy` wants epoch 2 gone, but `MetricCheckpointingStrategy`
+        // still considers epoch 2 the best (lowest loss) and votes to delete epoch 3 instead.
+        // Since the children disagree, epoch 2 must not be deleted.
+        record(&store, 3, 0.4);
+        let actions = strategy.checkpointing(3, &store);
+        assert!(actions.contains(&CheckpointingAction::Save));
+        assert!(!actions.contains(&CheckpointingAction::Delete(2)));
+    }
+}
@@ -1,12 +1,17 @@
-use super::log::install_file_logger;
+use super::early_stopping::EarlyStoppingStrategy;
+use super::log::{ApplicationLoggerInstaller, FileApplicationLoggerInstaller};
+use super::summary::LearnerSummaryConfig;
 use super::Learner;
-use crate::checkpoint::{AsyncCheckpointer, FileCheckpointer};
+use crate::checkpoint::{
+    AsyncCheckpointer, CheckpointingStrategy, FileCheckpointer, KeepLastNStrategy,
+};
 use crate::components::LearnerComponentsMarker;
 use crate::learner::base::TrainingInterrupter;
 use crate::logger::{FileMetricLogger, MetricLogger};
 use crate::metric::callback::{
     default_renderer, MetricWrapper, Metrics, MetricsCallback, MetricsRenderer,
 };
+use crate::metric::store::EventStoreClient;
 use crate::metric::{Adaptor, Metric};
 use crate::{AsyncTrainerCallback, LearnerCheckpointer};
 use burn_core::lr_scheduler::LrScheduler;
@@ -14,6 +19,7 @@ use burn_core::module::ADModule;
 use burn_core::optim::Optimizer;
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::ADBackend;
+use std::sync::Arc;
 
 /// Struct to configure and create a [learner](Learner).
 pub struct LearnerBuilder<B, T, V, M, O, S>
@@ -44,7 +50,10 @@ where
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
     interrupter: TrainingInterrupter,
-    log_to_file: bool,
+    application_logger: Option<Box<dyn ApplicationLoggerInstaller>>,
+    early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    checkpointing_strategy: Option<Box<dyn CheckpointingStrategy>>,
+    summary: LearnerSummaryConfig,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -74,7 +83,12 @@ where
             metrics: Metrics::new(),
             renderer: None,
             interrupter: TrainingInterrupter::new(),
-            log_to_file: true,
+            application_logger: Some(Box::new(FileApplicationLoggerInstaller::new(format!(
+                "{directory}/experiment.log"
+            )))),
+            early_stopping: None,
+            checkpointing_strategy: None,
+            summary: LearnerSummaryConfig::default(),
         }
     }
 
@@ -112,6 +126,7 @@ where
     where
         T: Adaptor<Me::Input>,
     {
+        self.summary.metrics.push(metric.name());
         self.metrics
             .train
             .push(Box::new(MetricWrapper::new(metric)));
@@ -123,6 +138,7 @@ where
     where
         V: Adaptor<Me::Input>,
     {
+        self.summary.metrics.push(metric.name());
         self.metrics
             .valid
             .push(Box::new(MetricWrapper::new(metric)));
@@ -156,6 +172,7 @@ where
         Me: Metric + crate::metric::Numeric + 'static,
         T: Adaptor<Me::Input>,
     {
+        self.summary.metrics.push(metric.name());
         self.metrics
             .train_numeric
             .push(Box::new(MetricWrapper::new(metric)));
@@ -176,6 +193,7 @@ where
     where
         V: Adaptor<Me::Input>,
     {
+        self.summary.metrics.push(metric.name());
         self.metrics
             .valid_numeric
             .push(Box::new(MetricWrapper::new(metric)));
@@ -205,11 +223,45 @@ where
         self.interrupter.clone()
     }
 
-    /// By default, Rust logs are captured and written into
-    /// `experiment.log`. If disabled, standard Rust log handling
-    /// will apply.
-    pub fn log_to_file(mut self, enabled: bool) -> Self {
-        self.log_to_file = enabled;
+    /// Enable the end-of-training [summary](super::summary::LearnerSummary). Once training
+    /// completes, the summary is printed and made available through
+    /// [`Learner::summary`](super::Learner::summary).
+    pub fn summary(mut self) -> Self {
+        self.summary.enabled = true;
+        self
+    }
+
+    /// Register an [early stopping strategy](EarlyStoppingStrategy) to halt training
+    /// automatically once its monitored metric stops improving.
+    pub fn early_stopping<Strategy>(mut self, strategy: Strategy) -> Self
+    where
+        Strategy: EarlyStoppingStrategy + 'static,
+    {
+        self.early_stopping = Some(Box::new(strategy));
+        self
+    }
+
+    /// Configure the [installer](ApplicationLoggerInstaller) used to capture the application
+    /// logs (the Rust `log`/`tracing` output) while training.
+    ///
+    /// By default, logs are written into `experiment.log` in the learner's directory. Pass
+    /// `None` to leave the standard Rust log handling untouched.
+    pub fn with_application_logger(
+        mut self,
+        installer: Option<Box<dyn ApplicationLoggerInstaller>>,
+    ) -> Self {
+        self.application_logger = installer;
+        self
+    }
+
+    /// Register a [checkpointing strategy](CheckpointingStrategy) deciding which checkpoints
+    /// to save and delete. Defaults to keeping the checkpoints of the last `num_keep` epochs
+    /// passed to [`with_file_checkpointer`](Self::with_file_checkpointer).
+    pub fn with_checkpointing_strategy<CS>(mut self, strategy: CS) -> Self
+    where
+        CS: CheckpointingStrategy + 'static,
+    {
+        self.checkpointing_strategy = Some(Box::new(strategy));
         self
     }
 
@@ -251,12 +303,21 @@ where
             AsyncCheckpointer::new(checkpointer_scheduler),
         ));
 
+        if self.checkpointing_strategy.is_none() {
+            self.checkpointing_strategy = Some(Box::new(KeepLastNStrategy::new(num_keep)));
+        }
+
         self
     }
 
     /// Create the [learner](Learner) from a [model](ADModule) and an [optimizer](Optimizer).
     /// The [learning rate scheduler](LrScheduler) can also be a simple
     /// [learning rate](burn_core::LearningRate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured [application logger](ApplicationLoggerInstaller)
+    /// fails to install.
     #[allow(clippy::type_complexity)] // The goal for the builder is to handle all types and
                                       // creates a clean learner.
     pub fn build(
@@ -264,26 +325,30 @@ where
         model: M,
         optim: O,
         lr_scheduler: S,
-    ) -> Learner<
-        LearnerComponentsMarker<
-            B,
-            S,
-            M,
-            O,
-            AsyncCheckpointer<M::Record>,
-            AsyncCheckpointer<O::Record>,
-            AsyncCheckpointer<S::Record>,
-            AsyncTrainerCallback<T, V>,
+    ) -> Result<
+        Learner<
+            LearnerComponentsMarker<
+                B,
+                S,
+                M,
+                O,
+                AsyncCheckpointer<M::Record>,
+                AsyncCheckpointer<O::Record>,
+                AsyncCheckpointer<S::Record>,
+                AsyncTrainerCallback<T, V>,
+            >,
         >,
+        String,
     >
     where
         M::Record: 'static,
         O::Record: 'static,
         S::Record: 'static,
     {
-        if self.log_to_file {
-            self.init_logger();
+        if let Some(installer) = self.application_logger {
+            installer.install()?;
         }
+
         let renderer = self.renderer.unwrap_or_else(|| {
             Box::new(default_renderer(self.interrupter.clone(), self.checkpoint))
         });
@@ -294,18 +359,20 @@ where
         let logger_valid = self.metric_logger_valid.unwrap_or_else(|| {
             Box::new(FileMetricLogger::new(format!("{directory}/valid").as_str()))
         });
+        let event_store = Arc::new(EventStoreClient::default());
         let callback = AsyncTrainerCallback::new(MetricsCallback::new(
             renderer,
             self.metrics,
             logger_train,
             logger_valid,
+            event_store.clone(),
         ));
 
-        let checkpointer = self
-            .checkpointers
-            .map(|(model, optim, scheduler)| LearnerCheckpointer::new(model, optim, scheduler));
+        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
+            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointing_strategy)
+        });
 
-        Learner {
+        Ok(Learner {
             model,
             optim,
             lr_scheduler,
@@ -316,11 +383,9 @@ where
             grad_accumulation: self.grad_accumulation,
             devices: self.devices,
             interrupter: self.interrupter,
-        }
-    }
-
-    fn init_logger(&self) {
-        let file_path = format!("{}/experiment.log", self.directory);
-        install_file_logger(file_path.as_str());
+            event_store,
+            early_stopping: self.early_stopping,
+            summary: self.summary,
+        })
     }
 }
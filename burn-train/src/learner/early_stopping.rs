@@ -0,0 +1,122 @@
+use crate::metric::store::{Aggregate, Direction, EventStoreClient, Split};
+
+/// A strategy consulted after every epoch to decide whether training should stop early.
+pub trait EarlyStoppingStrategy {
+    /// Returns `true` if training should stop, given the current `epoch` and the recorded
+    /// `store`.
+    fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool;
+}
+
+/// The condition that triggers an [early stopping strategy](EarlyStoppingStrategy).
+pub enum StoppingCondition {
+    /// Stop when the monitored metric hasn't improved for `n_epochs` epochs.
+    NoImprovementSince {
+        /// The number of epochs without improvement tolerated before stopping.
+        n_epochs: usize,
+    },
+}
+
+/// An [early stopping strategy](EarlyStoppingStrategy) that monitors a single metric.
+pub struct MetricEarlyStoppingStrategy {
+    name: String,
+    aggregate: Aggregate,
+    direction: Direction,
+    split: Split,
+    condition: StoppingCondition,
+}
+
+impl MetricEarlyStoppingStrategy {
+    /// Create a new metric early stopping strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the metric to monitor.
+    /// * `aggregate` - How the metric's values are aggregated over an epoch.
+    /// * `direction` - Whether a lower or higher aggregated value is better.
+    /// * `split` - The split the metric is monitored on.
+    /// * `condition` - The condition that triggers the stop.
+    pub fn new(
+        name: &str,
+        aggregate: Aggregate,
+        direction: Direction,
+        split: Split,
+        condition: StoppingCondition,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            aggregate,
+            direction,
+            split,
+            condition,
+        }
+    }
+}
+
+impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
+    fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool {
+        let best_epoch =
+            match store.find_epoch(&self.name, self.aggregate, self.direction, self.split) {
+                Some(epoch) => epoch,
+                None => return false,
+            };
+
+        match &self.condition {
+            StoppingCondition::NoImprovementSince { n_epochs } => {
+                epoch.saturating_sub(best_epoch) >= *n_epochs
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::store::{Event, NumericEntry};
+
+    fn record(store: &EventStoreClient, epoch: usize, value: f64) {
+        store.add_event(Event::MetricsUpdate {
+            metric_name: "loss".to_string(),
+            split: Split::Valid,
+            epoch,
+            aggregate: NumericEntry::Value(value),
+        });
+    }
+
+    #[test]
+    fn stops_after_n_epochs_without_improvement() {
+        let store = EventStoreClient::default();
+        let mut strategy = MetricEarlyStoppingStrategy::new(
+            "loss",
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Valid,
+            StoppingCondition::NoImprovementSince { n_epochs: 2 },
+        );
+
+        record(&store, 1, 0.5);
+        assert!(!strategy.should_stop(1, &store));
+
+        record(&store, 2, 0.3);
+        assert!(!strategy.should_stop(2, &store));
+
+        record(&store, 3, 0.4);
+        assert!(!strategy.should_stop(3, &store));
+
+        record(&store, 4, 0.4);
+        assert!(strategy.should_stop(4, &store));
+    }
+
+    #[test]
+    fn never_stops_without_recorded_metrics() {
+        let store = EventStoreClient::default();
+        let mut strategy = MetricEarlyStoppingStrategy::new(
+            "loss",
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Valid,
+            StoppingCondition::NoImprovementSince { n_epochs: 1 },
+        );
+
+        assert!(!strategy.should_stop(5, &store));
+    }
+}
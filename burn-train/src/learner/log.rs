@@ -0,0 +1,59 @@
+use tracing_core::{Level, LevelFilter};
+use tracing_subscriber::{filter::filter_fn, layer::SubscriberExt};
+
+/// Installs an application-wide logger (the Rust `log`/`tracing` output) when a
+/// [learner](crate::learner::Learner) is built.
+///
+/// Implement this trait to redirect training logs anywhere: rotating files, a custom
+/// subscriber, a remote sink, or nowhere at all.
+pub trait ApplicationLoggerInstaller: Send + Sync {
+    /// Install the logger, returning an error message on failure.
+    fn install(&self) -> Result<(), String>;
+}
+
+/// Installs a logger that writes the application logs to a file.
+///
+/// Third-party logs below `WARN` are dropped to keep the log file focused on the application
+/// itself; `burn` logs are exempt from that restriction, but the global max level is still
+/// capped at `INFO`, so `burn` events below that level (`DEBUG`, `TRACE`) are dropped as well.
+pub struct FileApplicationLoggerInstaller {
+    path: std::path::PathBuf,
+}
+
+impl FileApplicationLoggerInstaller {
+    /// Create a new installer that will write logs to the given `path`.
+    pub fn new<P: AsRef<std::path::Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl ApplicationLoggerInstaller for FileApplicationLoggerInstaller {
+    fn install(&self) -> Result<(), String> {
+        let file = std::fs::File::create(&self.path)
+            .map_err(|err| format!("unable to create the log file '{}': {err}", self.path.display()))?;
+
+        let layer = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .without_time()
+            .with_writer(std::sync::Mutex::new(file));
+
+        let filter = filter_fn(|metadata| {
+            if metadata.target().starts_with("burn") {
+                return true;
+            }
+            matches!(*metadata.level(), Level::WARN | Level::ERROR)
+        });
+
+        let subscriber = tracing_subscriber::registry()
+            .with(LevelFilter::INFO)
+            .with(filter)
+            .with(layer);
+
+        tracing::subscriber::set_global_default(subscriber)
+            .map_err(|err| format!("unable to install the file logger: {err}"))?;
+
+        Ok(())
+    }
+}
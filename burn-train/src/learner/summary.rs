@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use crate::metric::store::{Aggregate, EventStoreClient, Split};
+
+/// Configuration for the [end-of-training summary](LearnerSummary), built up by the
+/// [learner builder](super::LearnerBuilder) as metrics are registered.
+#[derive(Default, Clone)]
+pub(crate) struct LearnerSummaryConfig {
+    pub(crate) enabled: bool,
+    pub(crate) metrics: Vec<String>,
+}
+
+/// The series of aggregated values recorded for a single metric on a single split.
+pub struct LearnerSummaryMetric {
+    /// The split the metric was recorded on.
+    pub split: Split,
+    /// The `(epoch, value)` pairs recorded for the metric, in epoch order.
+    pub entries: Vec<(usize, f64)>,
+}
+
+/// A summary of how every registered metric evolved over the course of training.
+pub struct LearnerSummary {
+    /// The name of the model that was trained.
+    pub model: String,
+    /// The number of epochs the training ran for.
+    pub epochs: usize,
+    /// The recorded series for each metric, keyed by metric name.
+    pub metrics: HashMap<String, Vec<LearnerSummaryMetric>>,
+}
+
+impl LearnerSummary {
+    pub(crate) fn new(
+        model: String,
+        epochs: usize,
+        config: &LearnerSummaryConfig,
+        store: &EventStoreClient,
+    ) -> Self {
+        let mut metrics = HashMap::new();
+
+        for name in config.metrics.iter() {
+            let mut series = Vec::new();
+
+            for split in [Split::Train, Split::Valid] {
+                let entries: Vec<(usize, f64)> = (1..=epochs)
+                    .filter_map(|epoch| {
+                        store
+                            .find_metric(name, epoch, Aggregate::Mean, split)
+                            .map(|value| (epoch, value))
+                    })
+                    .collect();
+
+                if !entries.is_empty() {
+                    series.push(LearnerSummaryMetric { split, entries });
+                }
+            }
+
+            metrics.insert(name.clone(), series);
+        }
+
+        Self {
+            model,
+            epochs,
+            metrics,
+        }
+    }
+}
+
+impl Display for LearnerSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Summary for {} ({} epochs)", self.model, self.epochs)?;
+        writeln!(
+            f,
+            "{:<20} | {:<6} | {:<10} | {:<10} | {:<12}",
+            "Metric", "Split", "Min", "Max", "Final"
+        )?;
+
+        let mut names: Vec<_> = self.metrics.keys().collect();
+        names.sort();
+
+        for name in names {
+            for metric in self.metrics[name].iter() {
+                let values: Vec<f64> = metric.entries.iter().map(|(_, value)| *value).collect();
+                let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+                let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                let final_value = metric.entries.last().map(|(_, value)| *value).unwrap_or(0.0);
+                let split = match metric.split {
+                    Split::Train => "Train",
+                    Split::Valid => "Valid",
+                };
+
+                writeln!(
+                    f,
+                    "{:<20} | {:<6} | {:<10.3} | {:<10.3} | {:<12.3}",
+                    name, split, min, max, final_value
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+/// How numeric values recorded for a metric during an epoch should be aggregated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregate {
+    /// The mean of all recorded values.
+    Mean,
+}
+
+/// The direction in which an aggregated metric value is considered to be the best.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// A lower aggregated value is better (e.g. a loss).
+    Lowest,
+    /// A higher aggregated value is better (e.g. an accuracy).
+    Highest,
+}
+
+/// The split a metric was recorded on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Split {
+    /// The training split.
+    Train,
+    /// The validation split.
+    Valid,
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Aggregate, Direction, Split};
+
+/// A numeric value recorded for a metric at a given epoch.
+///
+/// Keeping the sample count alongside the aggregated value lets a running mean recovered
+/// from a log file (see [`Aggregated`](NumericEntry::Aggregated)) be combined correctly with
+/// values recorded later.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum NumericEntry {
+    /// A single raw value.
+    Value(f64),
+    /// A value aggregated from `count` underlying values.
+    Aggregated {
+        /// The aggregated value.
+        value: f64,
+        /// The number of underlying values the aggregate was computed from.
+        count: usize,
+    },
+}
+
+impl NumericEntry {
+    pub(crate) fn value(&self) -> f64 {
+        match self {
+            NumericEntry::Value(value) => *value,
+            NumericEntry::Aggregated { value, .. } => *value,
+        }
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        match self {
+            NumericEntry::Value(_) => 1,
+            NumericEntry::Aggregated { count, .. } => *count,
+        }
+    }
+
+    /// Combine this entry with another one recorded for the same metric/epoch, returning the
+    /// count-weighted mean of the two so that a running mean recovered from a log file
+    /// reconstructs correctly instead of being overwritten by the latest value.
+    pub(crate) fn merge(self, other: Self) -> Self {
+        let count = self.count() + other.count();
+        let value =
+            (self.value() * self.count() as f64 + other.value() * other.count() as f64)
+                / count as f64;
+
+        NumericEntry::Aggregated { value, count }
+    }
+}
+
+/// An event recorded during training that an [event store](EventStore) can react to.
+pub enum Event {
+    /// A metric was aggregated for the given `split` and `epoch`.
+    MetricsUpdate {
+        /// The name of the metric.
+        metric_name: String,
+        /// The split the metric was computed on.
+        split: Split,
+        /// The epoch the aggregated value belongs to.
+        epoch: usize,
+        /// The aggregated value.
+        aggregate: NumericEntry,
+    },
+    /// An epoch has ended.
+    EndEpoch(usize),
+}
+
+/// Collects [events](Event) and answers queries about the metrics recorded so far.
+pub trait EventStore: Send {
+    /// Record a new event.
+    fn add_event(&mut self, event: Event);
+
+    /// Find the epoch with the best aggregated value for the given metric/split, in the
+    /// given [direction](Direction).
+    fn find_epoch(
+        &mut self,
+        name: &str,
+        aggregate: Aggregate,
+        direction: Direction,
+        split: Split,
+    ) -> Option<usize>;
+
+    /// Find the aggregated value recorded for the given metric/epoch/split.
+    fn find_metric(
+        &mut self,
+        name: &str,
+        epoch: usize,
+        aggregate: Aggregate,
+        split: Split,
+    ) -> Option<f64>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_entry_round_trips_through_serde() {
+        for entry in [
+            NumericEntry::Value(0.5),
+            NumericEntry::Aggregated {
+                value: 0.5,
+                count: 4,
+            },
+        ] {
+            let serialized = serde_json::to_string(&entry).unwrap();
+            let deserialized: NumericEntry = serde_json::from_str(&serialized).unwrap();
+
+            assert_eq!(deserialized.value(), entry.value());
+            assert_eq!(deserialized.count(), entry.count());
+        }
+    }
+
+    #[test]
+    fn merge_computes_the_count_weighted_mean() {
+        let a = NumericEntry::Value(1.0);
+        let b = NumericEntry::Aggregated {
+            value: 3.0,
+            count: 3,
+        };
+
+        let merged = a.merge(b);
+
+        assert_eq!(merged.value(), 2.5);
+        assert_eq!(merged.count(), 4);
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::mpsc;
+
+use super::{log::LogEventStore, Aggregate, Direction, Event, EventStore, Split};
+
+enum Message {
+    OnEvent(Event),
+    FindEpoch(
+        String,
+        Aggregate,
+        Direction,
+        Split,
+        mpsc::SyncSender<Option<usize>>,
+    ),
+    FindMetric(
+        String,
+        usize,
+        Aggregate,
+        Split,
+        mpsc::SyncSender<Option<f64>>,
+    ),
+    End,
+}
+
+/// A thread-safe handle to an [event store](EventStore).
+///
+/// Events are pushed onto an internal channel and processed by a dedicated worker thread, so
+/// recording a metric never blocks the training loop on the store's bookkeeping.
+///
+/// This type is intentionally not `Clone`: dropping it shuts down the worker thread, so sharing
+/// it between the callback and the learner must go through a single handle, e.g.
+/// `Arc<EventStoreClient>`, rather than duplicating the shutdown signal across independent
+/// clones.
+pub struct EventStoreClient {
+    sender: mpsc::Sender<Message>,
+}
+
+impl Default for EventStoreClient {
+    fn default() -> Self {
+        Self::new(LogEventStore::default())
+    }
+}
+
+impl EventStoreClient {
+    pub(crate) fn new<C: EventStore + 'static>(store: C) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::spawn(move || Self::run(store, receiver));
+
+        Self { sender }
+    }
+
+    /// Record a new [event](Event).
+    pub fn add_event(&self, event: Event) {
+        self.sender
+            .send(Message::OnEvent(event))
+            .expect("Can send event to the event store worker.");
+    }
+
+    /// Find the epoch with the best aggregated value for the given metric/split.
+    pub fn find_epoch(
+        &self,
+        name: &str,
+        aggregate: Aggregate,
+        direction: Direction,
+        split: Split,
+    ) -> Option<usize> {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.sender
+            .send(Message::FindEpoch(
+                name.to_string(),
+                aggregate,
+                direction,
+                split,
+                sender,
+            ))
+            .expect("Can send message to the event store worker.");
+
+        receiver
+            .recv()
+            .expect("Can receive answer from the event store worker.")
+    }
+
+    /// Find the aggregated value recorded for the given metric/epoch/split.
+    pub fn find_metric(
+        &self,
+        name: &str,
+        epoch: usize,
+        aggregate: Aggregate,
+        split: Split,
+    ) -> Option<f64> {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.sender
+            .send(Message::FindMetric(
+                name.to_string(),
+                epoch,
+                aggregate,
+                split,
+                sender,
+            ))
+            .expect("Can send message to the event store worker.");
+
+        receiver
+            .recv()
+            .expect("Can receive answer from the event store worker.")
+    }
+
+    fn run<C: EventStore>(mut store: C, receiver: mpsc::Receiver<Message>) {
+        for message in receiver.iter() {
+            match message {
+                Message::OnEvent(event) => store.add_event(event),
+                Message::FindEpoch(name, aggregate, direction, split, sender) => sender
+                    .send(store.find_epoch(&name, aggregate, direction, split))
+                    .expect("Can send answer back to the caller."),
+                Message::FindMetric(name, epoch, aggregate, split, sender) => sender
+                    .send(store.find_metric(&name, epoch, aggregate, split))
+                    .expect("Can send answer back to the caller."),
+                Message::End => return,
+            }
+        }
+    }
+}
+
+impl Drop for EventStoreClient {
+    fn drop(&mut self) {
+        self.sender.send(Message::End).ok();
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use super::{Aggregate, Direction, Event, EventStore, NumericEntry, Split};
+
+/// An in-memory [event store](EventStore) that keeps one value per metric, split and epoch;
+/// values recorded more than once for the same epoch are merged into their count-weighted mean.
+#[derive(Default)]
+pub(crate) struct LogEventStore {
+    train: HashMap<String, HashMap<usize, NumericEntry>>,
+    valid: HashMap<String, HashMap<usize, NumericEntry>>,
+}
+
+impl LogEventStore {
+    fn split(&self, split: Split) -> &HashMap<String, HashMap<usize, NumericEntry>> {
+        match split {
+            Split::Train => &self.train,
+            Split::Valid => &self.valid,
+        }
+    }
+
+    fn split_mut(&mut self, split: Split) -> &mut HashMap<String, HashMap<usize, NumericEntry>> {
+        match split {
+            Split::Train => &mut self.train,
+            Split::Valid => &mut self.valid,
+        }
+    }
+}
+
+impl EventStore for LogEventStore {
+    fn add_event(&mut self, event: Event) {
+        if let Event::MetricsUpdate {
+            metric_name,
+            split,
+            epoch,
+            aggregate,
+        } = event
+        {
+            self.split_mut(split)
+                .entry(metric_name)
+                .or_default()
+                .entry(epoch)
+                .and_modify(|existing| *existing = existing.merge(aggregate))
+                .or_insert(aggregate);
+        }
+    }
+
+    fn find_epoch(
+        &mut self,
+        name: &str,
+        _aggregate: Aggregate,
+        direction: Direction,
+        split: Split,
+    ) -> Option<usize> {
+        let entries = self.split(split).get(name)?;
+
+        // Iterate in epoch order so that a tie is always broken in favor of the earliest
+        // epoch, regardless of the `HashMap`'s unspecified iteration order.
+        let mut epochs: Vec<usize> = entries.keys().copied().collect();
+        epochs.sort_unstable();
+
+        epochs.into_iter().reduce(|best, candidate| {
+            let ordering = f64::total_cmp(&entries[&candidate].value(), &entries[&best].value());
+            let candidate_is_better = match direction {
+                Direction::Lowest => ordering.is_lt(),
+                Direction::Highest => ordering.is_gt(),
+            };
+
+            if candidate_is_better {
+                candidate
+            } else {
+                best
+            }
+        })
+    }
+
+    fn find_metric(
+        &mut self,
+        name: &str,
+        epoch: usize,
+        _aggregate: Aggregate,
+        split: Split,
+    ) -> Option<f64> {
+        self.split(split)
+            .get(name)?
+            .get(&epoch)
+            .map(NumericEntry::value)
+    }
+}
@@ -0,0 +1,8 @@
+mod aggregate;
+mod base;
+mod client;
+mod log;
+
+pub use aggregate::{Aggregate, Direction, Split};
+pub use base::{Event, EventStore, NumericEntry};
+pub use client::EventStoreClient;